@@ -0,0 +1,55 @@
+//! Parses the binary's own `argv`, as opposed to the `commitlint.config.*` file.
+
+use crate::filters::SeverityFilter;
+use crate::settings::Severity;
+
+/// Everything the CLI invocation told us, independent of the rule config file.
+pub struct Cli {
+    pub fix_mode: bool,
+    pub allow_unsafe_fix: bool,
+    pub use_stdin: bool,
+    pub range: Option<String>,
+    pub path: Option<String>,
+    pub severity_filters: Vec<SeverityFilter>,
+}
+
+impl Cli {
+    /// Parses `args` (including `args[0]`, the binary name, which is skipped).
+    pub fn parse(args: &[String]) -> Cli {
+        let mut cli = Cli {
+            fix_mode: false,
+            allow_unsafe_fix: false,
+            use_stdin: false,
+            range: None,
+            path: None,
+            severity_filters: Vec::new(),
+        };
+
+        let mut iter = args.iter().skip(1);
+        while let Some(arg) = iter.next() {
+            match arg.as_str() {
+                "--fix" => cli.fix_mode = true,
+                "--unsafe" => cli.allow_unsafe_fix = true,
+                "--stdin" => cli.use_stdin = true,
+                "--range" => cli.range = iter.next().cloned(),
+                "--warn" | "--deny" | "--off" => {
+                    let severity = match arg.as_str() {
+                        "--warn" => Severity::Warning,
+                        "--deny" => Severity::Error,
+                        _ => Severity::Off,
+                    };
+                    if let Some(rule) = iter.next() {
+                        cli.severity_filters.push(SeverityFilter {
+                            rule: rule.clone(),
+                            severity,
+                        });
+                    }
+                }
+                _ if !arg.starts_with("--") && cli.path.is_none() => cli.path = Some(arg.clone()),
+                _ => {}
+            }
+        }
+
+        cli
+    }
+}
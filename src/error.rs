@@ -0,0 +1,129 @@
+//! Errors in commitlint's own configuration, kept separate from the `Report`s a rule
+//! produces for a commit that fails it.
+
+use crate::rules::KNOWN_RULE_NAMES;
+use crate::settings::RulesConfig;
+use miette::Diagnostic;
+use regex::Regex;
+use thiserror::Error;
+
+/// Something wrong with `commitlint.config.toml`/`.json` itself: it couldn't be read
+/// or parsed, a value didn't match the expected shape (wrong option-tuple arity, an
+/// unrecognised severity/condition/case), it referenced a rule that doesn't exist, an
+/// `ignore` pattern isn't a valid regex, or a CLI `--warn`/`--deny`/`--off` filter named
+/// a rule with no config to pair its severity with.
+#[derive(Debug, Error, Diagnostic)]
+pub enum ConfigError {
+    #[error("failed to read or parse configuration: {0}")]
+    #[diagnostic(
+        code(config::invalid),
+        help(
+            "check commitlint.config.toml/.json for typos, an unknown rule key, or a \
+              rule option tuple with the wrong number of elements"
+        )
+    )]
+    Invalid(#[from] config::ConfigError),
+
+    #[error("unknown rule `{rule}`")]
+    #[diagnostic(
+        code(config::unknown_rule),
+        help("rule names are e.g. `type-enum`, `subject-case`, `body-max-line-length`")
+    )]
+    UnknownRule { rule: String },
+
+    #[error("severity filter for `{rule}` has no config to pair the severity with")]
+    #[diagnostic(
+        code(config::filter_needs_config),
+        help("add a `[rules]` entry for this rule in commitlint.config.toml, since it has no universal default")
+    )]
+    FilterNeedsConfig { rule: String },
+
+    #[error("invalid ignore pattern `{pattern}`: {source}")]
+    #[diagnostic(
+        code(config::invalid_ignore_pattern),
+        help("`ignore.ignores` entries are regexes matched against the commit header")
+    )]
+    InvalidIgnorePattern {
+        pattern: String,
+        #[source]
+        source: regex::Error,
+    },
+}
+
+/// Checks parts of `config` that serde's field-level validation can't: the open-ended
+/// maps keyed by rule name rather than fixed struct fields, and the `ignore.ignores`
+/// regexes, which only fail when compiled.
+pub fn validate(config: &RulesConfig) -> Result<(), ConfigError> {
+    for rule in config.fix.keys() {
+        if !KNOWN_RULE_NAMES.contains(&rule.as_str()) {
+            return Err(ConfigError::UnknownRule { rule: rule.clone() });
+        }
+    }
+
+    for rule in config.help_url.rules.keys() {
+        if !KNOWN_RULE_NAMES.contains(&rule.as_str()) {
+            return Err(ConfigError::UnknownRule { rule: rule.clone() });
+        }
+    }
+
+    for pattern in &config.ignore.ignores {
+        if let Err(source) = Regex::new(pattern) {
+            return Err(ConfigError::InvalidIgnorePattern {
+                pattern: pattern.clone(),
+                source,
+            });
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::settings::{Fix, HelpUrls, IgnoreConfig, RulesDetails};
+    use std::collections::HashMap;
+
+    fn config() -> RulesConfig {
+        RulesConfig {
+            rules: RulesDetails::default(),
+            fix: HashMap::new(),
+            ignore: IgnoreConfig::default(),
+            help_url: HelpUrls::default(),
+        }
+    }
+
+    #[test]
+    fn accepts_a_config_with_nothing_to_validate() {
+        assert!(validate(&config()).is_ok());
+    }
+
+    #[test]
+    fn rejects_an_unknown_rule_in_fix() {
+        let mut cfg = config();
+        cfg.fix.insert("tpye-empty".to_string(), Fix::Safe);
+
+        let err = validate(&cfg).unwrap_err();
+        assert!(matches!(err, ConfigError::UnknownRule { rule } if rule == "tpye-empty"));
+    }
+
+    #[test]
+    fn rejects_an_unknown_rule_in_help_url_rules() {
+        let mut cfg = config();
+        cfg.help_url
+            .rules
+            .insert("tpye-empty".to_string(), "https://example.com".to_string());
+
+        let err = validate(&cfg).unwrap_err();
+        assert!(matches!(err, ConfigError::UnknownRule { rule } if rule == "tpye-empty"));
+    }
+
+    #[test]
+    fn rejects_a_malformed_ignore_pattern() {
+        let mut cfg = config();
+        cfg.ignore.ignores.push("(unterminated".to_string());
+
+        let err = validate(&cfg).unwrap_err();
+        assert!(matches!(err, ConfigError::InvalidIgnorePattern { pattern, .. } if pattern == "(unterminated"));
+    }
+}
@@ -0,0 +1,184 @@
+//! CLI severity filters (`--warn`/`--deny`/`--off <rule>`) that override the
+//! configured rules at invocation time, without editing `commitlint.config.toml`.
+
+use crate::error::ConfigError;
+use crate::settings::{Case, Condition, RulesDetails, Severity};
+
+/// One `--warn`/`--deny`/`--off <rule>` flag from the CLI.
+pub struct SeverityFilter {
+    pub rule: String,
+    pub severity: Severity,
+}
+
+/// Types allowed by the `@commitlint/config-conventional` preset, used as the
+/// `type-enum` default when the CLI turns the rule on without any config.
+const CONVENTIONAL_TYPES: &[&str] = &[
+    "build", "chore", "ci", "docs", "feat", "fix", "perf", "refactor", "revert", "style", "test",
+];
+
+/// Applies `filters` over `details`, so a team can tighten or relax enforcement from
+/// the CLI without editing `commitlint.config.toml`. A filter naming a rule that's
+/// already configured only overrides its severity; one naming an unconfigured rule
+/// turns it on with the same sensible default the conventional-commits preset uses, so
+/// `--deny type-empty` works on a config that never mentions `type-empty` at all.
+/// `scope-enum` has no universal default list of scopes, so enabling it from the CLI
+/// without an existing `scope-enum` entry is rejected instead of guessing. A filter
+/// naming a rule that doesn't exist at all (a typo like `tpye-empty`) is rejected the
+/// same way `fix`/`helpUrl.rules` reject unknown rule keys.
+pub fn apply(details: &mut RulesDetails, filters: &[SeverityFilter]) -> Result<(), ConfigError> {
+    for filter in filters {
+        let severity = filter.severity;
+        match filter.rule.as_str() {
+            "type-enum" => set_severity3(&mut details.type_enum, severity, || {
+                (
+                    Condition::Always,
+                    CONVENTIONAL_TYPES.iter().map(|t| t.to_string()).collect(),
+                )
+            }),
+            "type-case" => set_severity3(&mut details.type_case, severity, || {
+                (Condition::Always, Case::LowerCase)
+            }),
+            "type-empty" => set_severity2(&mut details.type_empty, severity, || Condition::Never),
+            "scope-enum" => match &mut details.scope_enum {
+                Some((s, _, _)) => *s = severity,
+                None => {
+                    return Err(ConfigError::FilterNeedsConfig {
+                        rule: filter.rule.clone(),
+                    })
+                }
+            },
+            "scope-case" => set_severity3(&mut details.scope_case, severity, || {
+                (Condition::Always, Case::LowerCase)
+            }),
+            "subject-empty" => {
+                set_severity2(&mut details.subject_empty, severity, || Condition::Never)
+            }
+            "subject-full-stop" => set_severity3(&mut details.subject_full_stop, severity, || {
+                (Condition::Never, '.')
+            }),
+            "subject-case" => set_severity3(&mut details.subject_case, severity, || {
+                (Condition::Never, Case::SentenceCase)
+            }),
+            "header-max-length" => set_severity3(&mut details.header_max_length, severity, || {
+                (Condition::Always, 100usize)
+            }),
+            "body-leading-blank" => {
+                set_severity2(&mut details.body_leading_blank, severity, || {
+                    Condition::Always
+                })
+            }
+            "body-max-line-length" => {
+                set_severity3(&mut details.body_max_line_length, severity, || {
+                    (Condition::Always, 100usize)
+                })
+            }
+            "footer-leading-blank" => {
+                set_severity2(&mut details.footer_leading_blank, severity, || {
+                    Condition::Always
+                })
+            }
+            _ => {
+                return Err(ConfigError::UnknownRule {
+                    rule: filter.rule.clone(),
+                })
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Overwrites the severity of a 2-element `(Severity, Condition)` option tuple,
+/// populating it with `default` first if the rule wasn't already configured.
+fn set_severity2<B: Clone>(
+    opts: &mut Option<(Severity, B)>,
+    severity: Severity,
+    default: impl FnOnce() -> B,
+) {
+    match opts {
+        Some((s, _)) => *s = severity,
+        None => *opts = Some((severity, default())),
+    }
+}
+
+/// Overwrites the severity of a 3-element `(Severity, Condition, _)` option tuple,
+/// populating it with `default` first if the rule wasn't already configured.
+fn set_severity3<B: Clone, C: Clone>(
+    opts: &mut Option<(Severity, B, C)>,
+    severity: Severity,
+    default: impl FnOnce() -> (B, C),
+) {
+    match opts {
+        Some((s, _, _)) => *s = severity,
+        None => {
+            let (b, c) = default();
+            *opts = Some((severity, b, c));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn turns_on_an_unconfigured_rule_with_its_preset_default() {
+        let mut details = RulesDetails::default();
+        let filters = [SeverityFilter {
+            rule: "type-empty".to_string(),
+            severity: Severity::Error,
+        }];
+
+        apply(&mut details, &filters).unwrap();
+
+        assert!(matches!(
+            details.type_empty,
+            Some((Severity::Error, Condition::Never))
+        ));
+    }
+
+    #[test]
+    fn overrides_the_severity_of_an_already_configured_rule() {
+        let mut details = RulesDetails {
+            type_empty: Some((Severity::Error, Condition::Never)),
+            ..Default::default()
+        };
+        let filters = [SeverityFilter {
+            rule: "type-empty".to_string(),
+            severity: Severity::Warning,
+        }];
+
+        apply(&mut details, &filters).unwrap();
+
+        assert!(matches!(
+            details.type_empty,
+            Some((Severity::Warning, Condition::Never))
+        ));
+    }
+
+    #[test]
+    fn rejects_enabling_scope_enum_without_existing_config() {
+        let mut details = RulesDetails::default();
+        let filters = [SeverityFilter {
+            rule: "scope-enum".to_string(),
+            severity: Severity::Error,
+        }];
+
+        let err = apply(&mut details, &filters).unwrap_err();
+
+        assert!(matches!(err, ConfigError::FilterNeedsConfig { rule } if rule == "scope-enum"));
+    }
+
+    #[test]
+    fn rejects_an_unknown_rule_name() {
+        let mut details = RulesDetails::default();
+        let filters = [SeverityFilter {
+            rule: "tpye-empty".to_string(),
+            severity: Severity::Error,
+        }];
+
+        let err = apply(&mut details, &filters).unwrap_err();
+
+        assert!(matches!(err, ConfigError::UnknownRule { rule } if rule == "tpye-empty"));
+    }
+}
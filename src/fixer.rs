@@ -0,0 +1,110 @@
+//! Applies the `fix` implementations of configured rules to a commit message.
+
+use crate::parser::parse_commit;
+use crate::rule::Rule;
+use crate::settings::Fix;
+use std::collections::HashMap;
+
+/// Repeatedly applies rule fixes to `message` until no more apply.
+///
+/// Each accepted fix can shift the byte spans the rest of the commit was parsed
+/// against, so the commit is re-parsed after every applied fix rather than trying to
+/// apply them all against one stale parse.
+pub fn fix_commit(
+    message: &str,
+    rules: &[Box<dyn Rule>],
+    fix_config: &HashMap<String, Fix>,
+    allow_unsafe: bool,
+) -> String {
+    let mut current = message.to_string();
+
+    loop {
+        let commit = parse_commit(&current);
+        let mut applied = false;
+
+        for rule in rules {
+            let mode = fix_config.get(rule.name()).copied().unwrap_or_default();
+            let may_fix = match mode {
+                Fix::None => false,
+                Fix::Safe => true,
+                Fix::Unsafe => allow_unsafe,
+            };
+            if !may_fix {
+                continue;
+            }
+
+            if let Some(fixed) = rule.fix(&commit) {
+                if fixed != current {
+                    current = fixed;
+                    applied = true;
+                    break;
+                }
+            }
+        }
+
+        if !applied {
+            return current;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::build_rules;
+    use crate::settings::{Case, Condition, HelpUrls, RulesDetails, Severity};
+
+    fn type_case_rules() -> Vec<Box<dyn Rule>> {
+        let details = RulesDetails {
+            type_case: Some((Severity::Error, Condition::Always, Case::LowerCase)),
+            ..Default::default()
+        };
+        build_rules(&details, &HelpUrls::default())
+    }
+
+    #[test]
+    fn applies_a_safe_fix() {
+        let rules = type_case_rules();
+        let fix_config = HashMap::from([("type-case".to_string(), Fix::Safe)]);
+
+        let fixed = fix_commit("FEAT(nice): add cool feature", &rules, &fix_config, false);
+
+        assert_eq!(fixed, "feat(nice): add cool feature");
+    }
+
+    #[test]
+    fn does_not_apply_a_fix_left_at_the_default_none() {
+        let rules = type_case_rules();
+
+        let fixed = fix_commit(
+            "FEAT(nice): add cool feature",
+            &rules,
+            &HashMap::new(),
+            false,
+        );
+
+        assert_eq!(fixed, "FEAT(nice): add cool feature");
+    }
+
+    #[test]
+    fn unsafe_fix_only_applies_when_explicitly_allowed() {
+        let rules = type_case_rules();
+        let fix_config = HashMap::from([("type-case".to_string(), Fix::Unsafe)]);
+
+        let not_allowed = fix_commit("FEAT(nice): add cool feature", &rules, &fix_config, false);
+        assert_eq!(not_allowed, "FEAT(nice): add cool feature");
+
+        let allowed = fix_commit("FEAT(nice): add cool feature", &rules, &fix_config, true);
+        assert_eq!(allowed, "feat(nice): add cool feature");
+    }
+
+    #[test]
+    fn converges_once_the_message_already_satisfies_the_rule() {
+        let rules = type_case_rules();
+        let fix_config = HashMap::from([("type-case".to_string(), Fix::Safe)]);
+
+        let fixed = fix_commit("feat(nice): add cool feature", &rules, &fix_config, false);
+
+        assert_eq!(fixed, "feat(nice): add cool feature");
+    }
+}
@@ -0,0 +1,68 @@
+//! Skips linting entirely for commits the author doesn't control, modeled on
+//! commitlint's `defaultIgnores` plus custom `ignores`.
+
+use crate::settings::IgnoreConfig;
+use regex::Regex;
+
+/// Patterns that match commits humans don't hand-write: merges, reverts, autosquash
+/// fixups, release version bumps, and the common dependency-bot integrations.
+const DEFAULT_IGNORE_PATTERNS: &[&str] = &[
+    r"^Merge (branch|pull request|remote-tracking branch) ",
+    r#"^Revert ".+"$"#,
+    r"^(fixup|squash)! ",
+    r"^v?\d+\.\d+\.\d+$",
+    r"^chore\(release\): ",
+    r"^(chore|build)\(deps\): (bump|update) ",
+];
+
+/// Whether `header` matches a default ignore pattern (unless disabled) or one of the
+/// user-supplied patterns in `config`.
+pub fn is_ignored(header: &str, config: &IgnoreConfig) -> bool {
+    if config.default_ignores && DEFAULT_IGNORE_PATTERNS.iter().any(|p| matches(p, header)) {
+        return true;
+    }
+
+    config
+        .ignores
+        .iter()
+        .any(|pattern| matches(pattern, header))
+}
+
+fn matches(pattern: &str, header: &str) -> bool {
+    Regex::new(pattern).is_ok_and(|re| re.is_match(header))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ignores_a_merge_commit_by_default() {
+        let config = IgnoreConfig::default();
+        assert!(is_ignored("Merge branch 'main' into feature", &config));
+    }
+
+    #[test]
+    fn does_not_ignore_a_regular_commit() {
+        let config = IgnoreConfig::default();
+        assert!(!is_ignored("feat: add cool feature", &config));
+    }
+
+    #[test]
+    fn default_ignores_can_be_disabled() {
+        let config = IgnoreConfig {
+            default_ignores: false,
+            ignores: Vec::new(),
+        };
+        assert!(!is_ignored("Merge branch 'main' into feature", &config));
+    }
+
+    #[test]
+    fn matches_a_custom_ignore_pattern() {
+        let config = IgnoreConfig {
+            default_ignores: false,
+            ignores: vec![r"^wip: ".to_string()],
+        };
+        assert!(is_ignored("wip: not ready yet", &config));
+    }
+}
@@ -1,116 +1,90 @@
+mod cli;
+mod error;
+mod filters;
+mod fixer;
+mod ignore;
+mod outcome;
 mod parser;
-
-use config::Config;
-use miette::{miette, LabeledSpan, Report, Result};
-use parser::{parse_commit, Commit};
-use serde::Deserialize;
-
-fn rule_scope_enum(commit: &Commit, opts: &ScopeEnumOpts) -> Option<Report> {
-    let severity = &opts.0;
-    let condition = &opts.1;
-    let scopes = &opts.2;
-
-    if severity == &Severity::Off {
-        return None;
-    }
-
-    if let Some(scope) = &commit.scope {
-        let is_in_scopes = scopes.contains(&scope.to_string());
-        let is_valid = match condition {
-            Condition::Never => !is_in_scopes,
-            Condition::Always => is_in_scopes,
-        };
-        if !is_valid {
-            return Some(
-                miette!(
-                    severity = match severity {
-                        Severity::Warning => miette::Severity::Warning,
-                        Severity::Error => miette::Severity::Error,
-                        Severity::Off => miette::Severity::Advice,
-                    },
-                    labels = vec![LabeledSpan::at(
-                        scope.start()..scope.end(),
-                        "not allowed scope"
-                    ),],
-                    help = String::from("scope must") + match condition {
-                        Condition::Never => " not",
-                        Condition::Always => "",
-                    } + " be one of " + &scopes.join(", "),
-                    code = "rule/scope-enum",
-                    url = "https://example.com",
-                    "Scope not allowed",
-                )
-                .with_source_code(commit.raw.clone()),
-            );
-        }
-    }
-
-    None
-}
-
-/// Severity of the rule
-#[derive(Debug, Deserialize, PartialEq)]
-enum Severity {
-    /// Turn off the rule
-    #[serde(rename = "off")]
-    Off,
-    /// Warn about the violation of a rule
-    #[serde(rename = "warning")]
-    Warning,
-    /// Error about the violation of a rule
-    #[serde(rename = "error")]
-    Error,
-}
-
-/// When the rule should be applied
-#[derive(Debug, Deserialize)]
-enum Condition {
-    /// The options should "never" be found (e.g. in a list of disallowed values)
-    #[serde(rename = "never")]
-    Never,
-    /// The options should "always" be found (e.g. in a list of allowed values)
-    #[serde(rename = "always")]
-    Always,
-}
-
-/// Options for the scope-enum rule
-type ScopeEnumOpts = (Severity, Condition, Vec<String>);
-
-/// Config all the rules
-#[derive(Debug, Deserialize)]
-struct RulesDetails {
-    #[serde(rename = "scope-enum")]
-    scope_enum: ScopeEnumOpts,
-}
-
-/// Config
-#[derive(Debug, Deserialize)]
-struct RulesConfig {
-    rules: RulesDetails,
-}
+mod rule;
+mod rules;
+mod settings;
+mod source;
+
+use ::config::Config;
+use cli::Cli;
+use error::ConfigError;
+use miette::Result;
+use settings::RulesConfig;
+use std::path::Path;
 
 fn main() -> Result<()> {
-    let commit_message =
-        "feat(nice): add cool feature\n\nsome body\n\nsecond body line\n\nsome footer";
-
-    let commit = parse_commit(&commit_message);
-    println!("{:#?}", commit);
-
-    let settings = Config::builder()
+    let args: Vec<String> = std::env::args().collect();
+    let cli = Cli::parse(&args);
+
+    // Resolve the commit(s) to lint: a `git log` range, stdin, a file path (e.g.
+    // `.git/COMMIT_EDITMSG` from a `commit-msg` hook), or a demo message as a fallback
+    // when the binary is run with none of the above.
+    let commits = if let Some(range) = &cli.range {
+        source::from_git_range(range)?
+    } else if cli.use_stdin {
+        vec![source::from_stdin()?]
+    } else if let Some(path) = &cli.path {
+        vec![source::from_path(Path::new(path))?]
+    } else {
+        vec![parser::parse_commit(
+            "feat(nice): add cool feature\n\nsome body\n\nsecond body line\n\nsome footer",
+        )]
+    };
+
+    let raw_settings = Config::builder()
         // Source can be `commitlint.config.toml` or `commitlint.config.json
-        .add_source(config::File::with_name("src/commitlint.config"))
+        .add_source(::config::File::with_name("src/commitlint.config"))
         // Add in settings from the environment (with a prefix of APP)
         // Eg.. `COMMITLINT_DEBUG=1 ./target/app` would set the `debug` key
-        .add_source(config::Environment::with_prefix("COMMITLINT"))
+        .add_source(::config::Environment::with_prefix("COMMITLINT"))
         .build()
-        .unwrap();
+        .map_err(ConfigError::Invalid)?;
+
+    let mut config: RulesConfig = raw_settings
+        .try_deserialize::<RulesConfig>()
+        .map_err(ConfigError::Invalid)?;
+    error::validate(&config)?;
+    filters::apply(&mut config.rules, &cli.severity_filters)?;
+
+    let rules = rules::build_rules(&config.rules, &config.help_url);
+
+    if cli.fix_mode {
+        for (index, commit) in commits.iter().enumerate() {
+            if index > 0 {
+                println!();
+            }
+            let fixed = if ignore::is_ignored(&commit.header.to_string(), &config.ignore) {
+                commit.raw.clone()
+            } else {
+                fixer::fix_commit(&commit.raw, &rules, &config.fix, cli.allow_unsafe_fix)
+            };
+            println!("{fixed}");
+        }
+        return Ok(());
+    }
+
+    let mut any_invalid = false;
+    for commit in &commits {
+        let result = if ignore::is_ignored(&commit.header.to_string(), &config.ignore) {
+            outcome::LintOutcome::skipped()
+        } else {
+            outcome::lint(commit, &rules)
+        };
 
-    // Print out our settings
-    let config: RulesConfig = settings.try_deserialize::<RulesConfig>().unwrap();
-    println!("{:?}", config);
+        for violation in result.errors.iter().chain(result.warnings.iter()) {
+            eprintln!("[{}] {:?}", violation.rule, violation.report);
+        }
+
+        any_invalid |= !result.valid;
+    }
 
-    if let Some(report) = rule_scope_enum(&commit, &config.rules.scope_enum) {
-        return Err(report);
+    if any_invalid {
+        std::process::exit(1);
     }
 
     Ok(())
@@ -0,0 +1,108 @@
+//! Aggregates every rule's verdict on a commit instead of stopping at the first one.
+
+use crate::parser::Commit;
+use crate::rule::Rule;
+use miette::Report;
+
+/// One rule's diagnostic for a commit.
+pub struct RuleViolation {
+    pub rule: &'static str,
+    pub report: Report,
+}
+
+/// The result of running every configured rule against a single commit message.
+pub struct LintOutcome {
+    /// `false` if at least one error-level rule failed. Warnings alone keep this `true`.
+    pub valid: bool,
+    pub errors: Vec<RuleViolation>,
+    pub warnings: Vec<RuleViolation>,
+}
+
+/// Runs every rule in `rules` against `commit`, bucketing violations by severity.
+///
+/// Every rule is evaluated, so callers see all problems with a commit at once rather
+/// than one at a time.
+pub fn lint(commit: &Commit, rules: &[Box<dyn Rule>]) -> LintOutcome {
+    let mut errors = Vec::new();
+    let mut warnings = Vec::new();
+
+    for rule in rules {
+        let Some(report) = rule.run(commit) else {
+            continue;
+        };
+
+        let violation = RuleViolation {
+            rule: rule.name(),
+            report,
+        };
+        match violation.report.severity() {
+            Some(miette::Severity::Warning) => warnings.push(violation),
+            _ => errors.push(violation),
+        }
+    }
+
+    LintOutcome {
+        valid: errors.is_empty(),
+        errors,
+        warnings,
+    }
+}
+
+impl LintOutcome {
+    /// An outcome for a commit that was skipped entirely, e.g. because it matched an
+    /// ignore pattern. Always valid, since no rule was given a chance to fail it.
+    pub fn skipped() -> Self {
+        Self {
+            valid: true,
+            errors: Vec::new(),
+            warnings: Vec::new(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::parse_commit;
+    use crate::rules::build_rules;
+    use crate::settings::{Condition, HelpUrls, RulesDetails, Severity};
+
+    #[test]
+    fn valid_when_no_rule_violates() {
+        let rules = build_rules(&RulesDetails::default(), &HelpUrls::default());
+        let commit = parse_commit("feat: add cool feature");
+        let outcome = lint(&commit, &rules);
+
+        assert!(outcome.valid);
+        assert!(outcome.errors.is_empty());
+        assert!(outcome.warnings.is_empty());
+    }
+
+    #[test]
+    fn buckets_error_and_warning_violations_separately() {
+        let details = RulesDetails {
+            type_empty: Some((Severity::Error, Condition::Always)),
+            subject_empty: Some((Severity::Warning, Condition::Always)),
+            ..Default::default()
+        };
+        let rules = build_rules(&details, &HelpUrls::default());
+        let commit = parse_commit("feat: add cool feature");
+
+        let outcome = lint(&commit, &rules);
+
+        assert!(!outcome.valid);
+        assert_eq!(outcome.errors.len(), 1);
+        assert_eq!(outcome.errors[0].rule, "type-empty");
+        assert_eq!(outcome.warnings.len(), 1);
+        assert_eq!(outcome.warnings[0].rule, "subject-empty");
+    }
+
+    #[test]
+    fn skipped_is_always_valid_with_no_violations() {
+        let outcome = LintOutcome::skipped();
+
+        assert!(outcome.valid);
+        assert!(outcome.errors.is_empty());
+        assert!(outcome.warnings.is_empty());
+    }
+}
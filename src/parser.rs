@@ -0,0 +1,349 @@
+//! Parses a raw commit message into the structural parts the rules operate on.
+
+use std::fmt;
+use std::ops::Range;
+
+/// A value together with the byte range it occupies in the original commit message.
+///
+/// Keeping the range alongside the parsed value lets rules build `miette` labels that
+/// point at the exact slice of `Commit::raw` that caused a violation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Spanned<T> {
+    value: T,
+    range: Range<usize>,
+}
+
+impl<T> Spanned<T> {
+    pub fn new(value: T, range: Range<usize>) -> Self {
+        Self { value, range }
+    }
+
+    pub fn value(&self) -> &T {
+        &self.value
+    }
+
+    pub fn start(&self) -> usize {
+        self.range.start
+    }
+
+    pub fn end(&self) -> usize {
+        self.range.end
+    }
+
+    pub fn len(&self) -> usize {
+        self.range.end - self.range.start
+    }
+}
+
+impl fmt::Display for Spanned<String> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
+
+/// A commit message broken down into the pieces the Conventional Commits rules inspect.
+#[derive(Debug)]
+pub struct Commit {
+    /// The full, unmodified commit message.
+    pub raw: String,
+    /// The first line of the message.
+    pub header: Spanned<String>,
+    /// The `type` prefix, e.g. `feat` in `feat(nice): add cool feature`.
+    pub r#type: Option<Spanned<String>>,
+    /// The `scope` in parentheses, e.g. `nice` in `feat(nice): add cool feature`.
+    pub scope: Option<Spanned<String>>,
+    /// Whether the header marks a breaking change with a `!` before the colon.
+    ///
+    /// No rule in this tree reads it yet (there is no `header-breaking` rule), but it's
+    /// part of the parsed structure callers of `Commit` are expected to rely on.
+    #[allow(dead_code)]
+    pub breaking: bool,
+    /// The text after `type(scope):`.
+    pub subject: Option<Spanned<String>>,
+    /// Every paragraph between the header and the footers, joined back together.
+    pub body: Option<Spanned<String>>,
+    /// Trailing `Key: value`/`BREAKING CHANGE: ...` paragraphs.
+    pub footers: Vec<Spanned<String>>,
+    /// The full commit SHA, when the commit was resolved from git history.
+    pub sha: Option<String>,
+    /// The abbreviated (7-character) commit SHA, when resolved from git history.
+    pub short_sha: Option<String>,
+    /// The author's email address, when resolved from git history.
+    pub author_email: Option<String>,
+}
+
+/// Splits `raw` into paragraphs separated by one or more blank lines, tracking each
+/// paragraph's byte range in `raw`.
+fn paragraphs(raw: &str) -> Vec<Range<usize>> {
+    let mut result = Vec::new();
+    let mut start: Option<usize> = None;
+    let mut last_content_end = 0;
+    let mut cursor = 0;
+
+    for line in raw.split_inclusive('\n') {
+        let trimmed = line.trim_end_matches('\n');
+        if trimmed.trim().is_empty() {
+            if let Some(s) = start.take() {
+                result.push(s..last_content_end);
+            }
+        } else {
+            if start.is_none() {
+                start = Some(cursor);
+            }
+            last_content_end = cursor + trimmed.len();
+        }
+        cursor += line.len();
+    }
+    if let Some(s) = start.take() {
+        result.push(s..last_content_end);
+    }
+
+    result
+}
+
+/// A paragraph counts as a footer when every one of its lines looks like a git trailer,
+/// e.g. `Reviewed-by: Jane Doe` or `BREAKING CHANGE: ...`.
+fn looks_like_footer(paragraph: &str) -> bool {
+    paragraph.lines().all(|line| {
+        if let Some(rest) = line.strip_prefix("BREAKING CHANGE") {
+            return rest.starts_with(':');
+        }
+        match line.find(": ") {
+            Some(idx) => {
+                let token = &line[..idx];
+                !token.is_empty() && token.chars().all(|c| c.is_alphanumeric() || c == '-')
+            }
+            None => false,
+        }
+    })
+}
+
+/// Returns `raw` with the byte range `range` replaced by `replacement`.
+///
+/// Used by rule `fix` implementations to rewrite a single spanned part of a commit
+/// message without having to rebuild the whole string by hand.
+pub fn replace_span(raw: &str, range: Range<usize>, replacement: &str) -> String {
+    let mut fixed =
+        String::with_capacity(raw.len() - (range.end - range.start) + replacement.len());
+    fixed.push_str(&raw[..range.start]);
+    fixed.push_str(replacement);
+    fixed.push_str(&raw[range.end..]);
+    fixed
+}
+
+/// Parses a raw commit message into a [`Commit`].
+pub fn parse_commit(raw: &str) -> Commit {
+    let header_end = raw.find('\n').unwrap_or(raw.len());
+    let header_text = raw[..header_end].to_string();
+    let header = Spanned::new(header_text.clone(), 0..header_end);
+
+    let ParsedHeader {
+        r#type,
+        scope,
+        breaking,
+        subject,
+    } = parse_header(&header_text, 0);
+
+    let paragraphs = paragraphs(raw);
+    // Lines right after the header with no blank line in between share the header's
+    // paragraph; they still start the body (or footers), so split them off the header.
+    let rest = paragraphs
+        .into_iter()
+        .filter_map(|p| {
+            if p.start >= header_end {
+                Some(p)
+            } else if p.end > header_end {
+                Some(header_end + 1..p.end)
+            } else {
+                None
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let mut footer_ranges = Vec::new();
+    let mut body_ranges = rest;
+    while let Some(last) = body_ranges.last() {
+        if looks_like_footer(&raw[last.clone()]) {
+            footer_ranges.insert(0, body_ranges.pop().unwrap());
+        } else {
+            break;
+        }
+    }
+
+    let body = match (body_ranges.first(), body_ranges.last()) {
+        (Some(first), Some(last)) => {
+            let range = first.start..last.end;
+            Some(Spanned::new(raw[range.clone()].to_string(), range))
+        }
+        _ => None,
+    };
+
+    let footers = footer_ranges
+        .into_iter()
+        .map(|range| Spanned::new(raw[range.clone()].to_string(), range))
+        .collect();
+
+    Commit {
+        raw: raw.to_string(),
+        header,
+        r#type,
+        scope,
+        breaking,
+        subject,
+        body,
+        footers,
+        sha: None,
+        short_sha: None,
+        author_email: None,
+    }
+}
+
+/// The structured parts `parse_header` pulls out of a commit header.
+struct ParsedHeader {
+    r#type: Option<Spanned<String>>,
+    scope: Option<Spanned<String>>,
+    breaking: bool,
+    subject: Option<Spanned<String>>,
+}
+
+/// Parses `type(scope)!: subject` out of a header, offsetting spans by `offset`.
+fn parse_header(header: &str, offset: usize) -> ParsedHeader {
+    let Some(colon_idx) = header.find(": ") else {
+        return ParsedHeader {
+            r#type: None,
+            scope: None,
+            breaking: false,
+            subject: None,
+        };
+    };
+
+    let prefix = &header[..colon_idx];
+    let subject_start = colon_idx + 2;
+    let subject = if subject_start < header.len() {
+        Some(Spanned::new(
+            header[subject_start..].to_string(),
+            offset + subject_start..offset + header.len(),
+        ))
+    } else {
+        None
+    };
+
+    let (prefix, breaking) = match prefix.strip_suffix('!') {
+        Some(stripped) => (stripped, true),
+        None => (prefix, false),
+    };
+
+    let (type_part, scope) = match prefix.find('(') {
+        Some(paren_start) if prefix.ends_with(')') => {
+            let scope_start = paren_start + 1;
+            let scope_end = prefix.len() - 1;
+            let scope = if scope_start < scope_end {
+                Some(Spanned::new(
+                    prefix[scope_start..scope_end].to_string(),
+                    offset + scope_start..offset + scope_end,
+                ))
+            } else {
+                None
+            };
+            (&prefix[..paren_start], scope)
+        }
+        _ => (prefix, None),
+    };
+
+    let r#type = if type_part.is_empty() {
+        None
+    } else {
+        Some(Spanned::new(
+            type_part.to_string(),
+            offset..offset + type_part.len(),
+        ))
+    };
+
+    ParsedHeader {
+        r#type,
+        scope,
+        breaking,
+        subject,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_type_scope_and_subject() {
+        let commit = parse_commit("feat(nice): add cool feature");
+        assert_eq!(commit.r#type.unwrap().value(), "feat");
+        assert_eq!(commit.scope.unwrap().value(), "nice");
+        assert!(!commit.breaking);
+        assert_eq!(commit.subject.unwrap().value(), "add cool feature");
+    }
+
+    #[test]
+    fn parses_breaking_marker_without_scope() {
+        let commit = parse_commit("feat!: add cool feature");
+        assert_eq!(commit.r#type.unwrap().value(), "feat");
+        assert!(commit.scope.is_none());
+        assert!(commit.breaking);
+    }
+
+    #[test]
+    fn header_without_a_colon_has_no_structured_parts() {
+        let commit = parse_commit("add cool feature");
+        assert!(commit.r#type.is_none());
+        assert!(commit.scope.is_none());
+        assert!(commit.subject.is_none());
+        assert_eq!(commit.header.value(), "add cool feature");
+    }
+
+    #[test]
+    fn empty_subject_after_colon_is_none() {
+        let commit = parse_commit("feat: ");
+        assert_eq!(commit.r#type.unwrap().value(), "feat");
+        assert!(commit.subject.is_none());
+    }
+
+    #[test]
+    fn multi_paragraph_body_and_trailing_footers() {
+        let commit = parse_commit(
+            "feat(nice): add cool feature\n\nsome body\n\nsecond body line\n\nReviewed-by: Jane Doe\nCloses: #1",
+        );
+        assert_eq!(
+            commit.body.unwrap().value(),
+            "some body\n\nsecond body line"
+        );
+        assert_eq!(commit.footers.len(), 1);
+        assert_eq!(
+            commit.footers[0].value(),
+            "Reviewed-by: Jane Doe\nCloses: #1"
+        );
+    }
+
+    #[test]
+    fn breaking_change_paragraph_is_a_footer_on_its_own() {
+        let commit = parse_commit(
+            "feat(nice): add cool feature\n\nsome body\n\nBREAKING CHANGE: rewrote the API",
+        );
+        assert_eq!(commit.body.unwrap().value(), "some body");
+        assert_eq!(commit.footers.len(), 1);
+        assert_eq!(
+            commit.footers[0].value(),
+            "BREAKING CHANGE: rewrote the API"
+        );
+    }
+
+    #[test]
+    fn no_body_all_footers() {
+        let commit = parse_commit("feat: add cool feature\n\nReviewed-by: Jane Doe");
+        assert!(commit.body.is_none());
+        assert_eq!(commit.footers.len(), 1);
+    }
+
+    #[test]
+    fn replace_span_rewrites_only_the_given_range() {
+        let raw = "feat(nice): add cool feature";
+        let fixed = replace_span(raw, 0..4, "fix");
+        assert_eq!(fixed, "fix(nice): add cool feature");
+    }
+}
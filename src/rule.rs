@@ -0,0 +1,43 @@
+//! The `Rule` trait every lint rule implements.
+
+use crate::parser::Commit;
+use miette::Report;
+
+/// A single lint rule that can be checked against a parsed commit.
+pub trait Rule {
+    /// Machine-readable rule code, e.g. `type-enum`, used in config and diagnostics.
+    fn name(&self) -> &'static str;
+
+    /// Checks `commit` against this rule, returning a diagnostic report on violation.
+    fn run(&self, commit: &Commit) -> Option<Report>;
+
+    /// Returns a rewritten commit message that resolves this rule's violation, if this
+    /// rule knows how to repair it deterministically. Rules that can't be fixed safely
+    /// (or at all) keep the default, which never rewrites anything.
+    fn fix(&self, _commit: &Commit) -> Option<String> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct NoOpRule;
+
+    impl Rule for NoOpRule {
+        fn name(&self) -> &'static str {
+            "no-op"
+        }
+
+        fn run(&self, _commit: &Commit) -> Option<Report> {
+            None
+        }
+    }
+
+    #[test]
+    fn default_fix_never_rewrites_the_commit() {
+        let commit = crate::parser::parse_commit("feat: add cool feature");
+        assert!(NoOpRule.fix(&commit).is_none());
+    }
+}
@@ -0,0 +1,91 @@
+use crate::parser::{replace_span, Commit};
+use crate::rule::Rule;
+use crate::settings::{Condition, EmptyOpts, Severity};
+use miette::{miette, LabeledSpan, Report};
+
+/// `body-leading-blank`: the body must (not) be preceded by a blank line.
+pub struct BodyLeadingBlankRule {
+    pub opts: EmptyOpts,
+    pub help_url: String,
+}
+
+impl Rule for BodyLeadingBlankRule {
+    fn name(&self) -> &'static str {
+        "body-leading-blank"
+    }
+
+    fn run(&self, commit: &Commit) -> Option<Report> {
+        let (severity, condition) = &self.opts;
+        if severity == &Severity::Off {
+            return None;
+        }
+
+        let body = commit.body.as_ref()?;
+        let gap = &commit.raw[commit.header.end()..body.start()];
+        let has_leading_blank = gap.matches('\n').count() >= 2;
+        let is_valid = match condition {
+            Condition::Never => !has_leading_blank,
+            Condition::Always => has_leading_blank,
+        };
+        if is_valid {
+            return None;
+        }
+
+        Some(
+            miette!(
+                severity = severity.to_miette(),
+                labels = vec![LabeledSpan::at(commit.header.end()..body.start(), "here")],
+                help = match condition {
+                    Condition::Never => "body must not start with a blank line",
+                    Condition::Always => "body must start with a blank line",
+                },
+                code = "rule/body-leading-blank",
+                url = self.help_url.clone(),
+                "Body leading blank line is invalid",
+            )
+            .with_source_code(commit.raw.clone()),
+        )
+    }
+
+    fn fix(&self, commit: &Commit) -> Option<String> {
+        let (_, condition) = &self.opts;
+        if !matches!(condition, Condition::Always) {
+            return None;
+        }
+
+        let body = commit.body.as_ref()?;
+        let gap = commit.header.end()..body.start();
+        if commit.raw[gap.clone()].matches('\n').count() >= 2 {
+            return None;
+        }
+
+        Some(replace_span(&commit.raw, gap, "\n\n"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::parse_commit;
+
+    fn rule(condition: Condition) -> BodyLeadingBlankRule {
+        BodyLeadingBlankRule {
+            opts: (Severity::Error, condition),
+            help_url: String::new(),
+        }
+    }
+
+    #[test]
+    fn allows_a_body_preceded_by_a_blank_line_when_always_required() {
+        let rule = rule(Condition::Always);
+        let commit = parse_commit("feat: add cool feature\n\nsome body");
+        assert!(rule.run(&commit).is_none());
+    }
+
+    #[test]
+    fn rejects_a_body_preceded_by_a_blank_line_when_never_allowed() {
+        let rule = rule(Condition::Never);
+        let commit = parse_commit("feat: add cool feature\n\nsome body");
+        assert!(rule.run(&commit).is_some());
+    }
+}
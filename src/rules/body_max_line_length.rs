@@ -0,0 +1,80 @@
+use crate::parser::Commit;
+use crate::rule::Rule;
+use crate::settings::{Condition, MaxLengthOpts, Severity};
+use miette::{miette, LabeledSpan, Report};
+
+/// `body-max-line-length`: no line of the body may (not) exceed a configured length.
+pub struct BodyMaxLineLengthRule {
+    pub opts: MaxLengthOpts,
+    pub help_url: String,
+}
+
+impl Rule for BodyMaxLineLengthRule {
+    fn name(&self) -> &'static str {
+        "body-max-line-length"
+    }
+
+    fn run(&self, commit: &Commit) -> Option<Report> {
+        let (severity, condition, max_length) = &self.opts;
+        if severity == &Severity::Off {
+            return None;
+        }
+
+        let body = commit.body.as_ref()?;
+        let mut offset = body.start();
+        for line in body.value().split('\n') {
+            let length = line.chars().count();
+            let is_within_length = length <= *max_length;
+            let is_valid = match condition {
+                Condition::Never => !is_within_length,
+                Condition::Always => is_within_length,
+            };
+            if !is_valid {
+                return Some(
+                    miette!(
+                        severity = severity.to_miette(),
+                        labels = vec![LabeledSpan::at(
+                            offset..offset + line.len(),
+                            format!("{length} characters")
+                        )],
+                        help = format!("each body line must be at most {max_length} characters"),
+                        code = "rule/body-max-line-length",
+                        url = self.help_url.clone(),
+                        "Body line is too long",
+                    )
+                    .with_source_code(commit.raw.clone()),
+                );
+            }
+            offset += line.len() + 1;
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::parse_commit;
+
+    fn rule(max_length: usize) -> BodyMaxLineLengthRule {
+        BodyMaxLineLengthRule {
+            opts: (Severity::Error, Condition::Always, max_length),
+            help_url: String::new(),
+        }
+    }
+
+    #[test]
+    fn allows_body_lines_within_the_limit() {
+        let rule = rule(100);
+        let commit = parse_commit("feat: add cool feature\n\nsome body");
+        assert!(rule.run(&commit).is_none());
+    }
+
+    #[test]
+    fn rejects_a_body_line_over_the_limit() {
+        let rule = rule(5);
+        let commit = parse_commit("feat: add cool feature\n\nsome body");
+        assert!(rule.run(&commit).is_some());
+    }
+}
@@ -0,0 +1,82 @@
+use crate::parser::Commit;
+use crate::rule::Rule;
+use crate::settings::{Condition, EmptyOpts, Severity};
+use miette::{miette, LabeledSpan, Report};
+
+/// `footer-leading-blank`: the first footer must (not) be preceded by a blank line.
+pub struct FooterLeadingBlankRule {
+    pub opts: EmptyOpts,
+    pub help_url: String,
+}
+
+impl Rule for FooterLeadingBlankRule {
+    fn name(&self) -> &'static str {
+        "footer-leading-blank"
+    }
+
+    fn run(&self, commit: &Commit) -> Option<Report> {
+        let (severity, condition) = &self.opts;
+        if severity == &Severity::Off {
+            return None;
+        }
+
+        let footer = commit.footers.first()?;
+        let preceding_end = commit
+            .body
+            .as_ref()
+            .map_or(commit.header.end(), |b| b.end());
+        let gap = &commit.raw[preceding_end..footer.start()];
+        let has_leading_blank = gap.matches('\n').count() >= 2;
+        let is_valid = match condition {
+            Condition::Never => !has_leading_blank,
+            Condition::Always => has_leading_blank,
+        };
+        if is_valid {
+            return None;
+        }
+
+        Some(
+            miette!(
+                severity = severity.to_miette(),
+                labels = vec![LabeledSpan::at(preceding_end..footer.start(), "here")],
+                help = match condition {
+                    Condition::Never => "footer must not start with a blank line",
+                    Condition::Always => "footer must start with a blank line",
+                },
+                code = "rule/footer-leading-blank",
+                url = self.help_url.clone(),
+                "Footer leading blank line is invalid",
+            )
+            .with_source_code(commit.raw.clone()),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::parse_commit;
+
+    fn rule(condition: Condition) -> FooterLeadingBlankRule {
+        FooterLeadingBlankRule {
+            opts: (Severity::Error, condition),
+            help_url: String::new(),
+        }
+    }
+
+    #[test]
+    fn allows_a_footer_preceded_by_a_blank_line_when_always_required() {
+        let rule = rule(Condition::Always);
+        let commit =
+            parse_commit("feat: add cool feature\n\nsome body\n\nReviewed-by: Jane Doe");
+        assert!(rule.run(&commit).is_none());
+    }
+
+    #[test]
+    fn rejects_a_footer_preceded_by_a_blank_line_when_never_allowed() {
+        let rule = rule(Condition::Never);
+        let commit =
+            parse_commit("feat: add cool feature\n\nsome body\n\nReviewed-by: Jane Doe");
+        assert!(rule.run(&commit).is_some());
+    }
+}
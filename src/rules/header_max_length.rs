@@ -0,0 +1,75 @@
+use crate::parser::Commit;
+use crate::rule::Rule;
+use crate::settings::{Condition, MaxLengthOpts, Severity};
+use miette::{miette, LabeledSpan, Report};
+
+/// `header-max-length`: the header must (not) be within a configured length.
+pub struct HeaderMaxLengthRule {
+    pub opts: MaxLengthOpts,
+    pub help_url: String,
+}
+
+impl Rule for HeaderMaxLengthRule {
+    fn name(&self) -> &'static str {
+        "header-max-length"
+    }
+
+    fn run(&self, commit: &Commit) -> Option<Report> {
+        let (severity, condition, max_length) = &self.opts;
+        if severity == &Severity::Off {
+            return None;
+        }
+
+        let length = commit.header.value().chars().count();
+        let is_within_length = length <= *max_length;
+        let is_valid = match condition {
+            Condition::Never => !is_within_length,
+            Condition::Always => is_within_length,
+        };
+        if is_valid {
+            return None;
+        }
+
+        Some(
+            miette!(
+                severity = severity.to_miette(),
+                labels = vec![LabeledSpan::at(
+                    0..commit.header.len(),
+                    format!("{length} characters")
+                )],
+                help = format!("header must be at most {max_length} characters"),
+                code = "rule/header-max-length",
+                url = self.help_url.clone(),
+                "Header is too long",
+            )
+            .with_source_code(commit.raw.clone()),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::parse_commit;
+
+    fn rule(max_length: usize) -> HeaderMaxLengthRule {
+        HeaderMaxLengthRule {
+            opts: (Severity::Error, Condition::Always, max_length),
+            help_url: String::new(),
+        }
+    }
+
+    #[test]
+    fn allows_a_header_within_the_limit() {
+        let rule = rule(100);
+        let commit = parse_commit("feat: add cool feature");
+        assert!(rule.run(&commit).is_none());
+    }
+
+    #[test]
+    fn rejects_a_header_over_the_limit() {
+        let rule = rule(10);
+        let commit = parse_commit("feat: add cool feature");
+        assert!(rule.run(&commit).is_some());
+    }
+}
@@ -0,0 +1,156 @@
+//! The Conventional Commits rule set and the registry that builds it from config.
+
+mod body_leading_blank;
+mod body_max_line_length;
+mod footer_leading_blank;
+mod header_max_length;
+mod scope_case;
+mod scope_enum;
+mod subject_case;
+mod subject_empty;
+mod subject_full_stop;
+mod type_case;
+mod type_empty;
+mod type_enum;
+
+use crate::rule::Rule;
+use crate::settings::{HelpUrls, RulesDetails};
+
+use body_leading_blank::BodyLeadingBlankRule;
+use body_max_line_length::BodyMaxLineLengthRule;
+use footer_leading_blank::FooterLeadingBlankRule;
+use header_max_length::HeaderMaxLengthRule;
+use scope_case::ScopeCaseRule;
+use scope_enum::ScopeEnumRule;
+use subject_case::SubjectCaseRule;
+use subject_empty::SubjectEmptyRule;
+use subject_full_stop::SubjectFullStopRule;
+use type_case::TypeCaseRule;
+use type_empty::TypeEmptyRule;
+use type_enum::TypeEnumRule;
+
+/// Every rule name the registry knows how to build, used to validate config keys that
+/// aren't already constrained by `RulesDetails`'s fields (e.g. `fix`, `helpUrl.rules`).
+pub const KNOWN_RULE_NAMES: &[&str] = &[
+    "type-enum",
+    "type-case",
+    "type-empty",
+    "scope-enum",
+    "scope-case",
+    "subject-empty",
+    "subject-full-stop",
+    "subject-case",
+    "header-max-length",
+    "body-leading-blank",
+    "body-max-line-length",
+    "footer-leading-blank",
+];
+
+/// Builds the list of rules to run against a commit from the configured options.
+///
+/// Only rules with an entry in `details` are run, so a fresh config that only sets
+/// `scope-enum` behaves exactly like the rest stayed unconfigured. Each rule's
+/// diagnostic links to `help_urls`' entry for it, falling back to the global default.
+pub fn build_rules(details: &RulesDetails, help_urls: &HelpUrls) -> Vec<Box<dyn Rule>> {
+    let mut rules: Vec<Box<dyn Rule>> = Vec::new();
+
+    if let Some(opts) = &details.type_enum {
+        rules.push(Box::new(TypeEnumRule {
+            opts: opts.clone(),
+            help_url: help_urls.for_rule("type-enum"),
+        }));
+    }
+    if let Some(opts) = &details.type_case {
+        rules.push(Box::new(TypeCaseRule {
+            opts: *opts,
+            help_url: help_urls.for_rule("type-case"),
+        }));
+    }
+    if let Some(opts) = &details.type_empty {
+        rules.push(Box::new(TypeEmptyRule {
+            opts: *opts,
+            help_url: help_urls.for_rule("type-empty"),
+        }));
+    }
+    if let Some(opts) = &details.scope_enum {
+        rules.push(Box::new(ScopeEnumRule {
+            opts: opts.clone(),
+            help_url: help_urls.for_rule("scope-enum"),
+        }));
+    }
+    if let Some(opts) = &details.scope_case {
+        rules.push(Box::new(ScopeCaseRule {
+            opts: *opts,
+            help_url: help_urls.for_rule("scope-case"),
+        }));
+    }
+    if let Some(opts) = &details.subject_empty {
+        rules.push(Box::new(SubjectEmptyRule {
+            opts: *opts,
+            help_url: help_urls.for_rule("subject-empty"),
+        }));
+    }
+    if let Some(opts) = &details.subject_full_stop {
+        rules.push(Box::new(SubjectFullStopRule {
+            opts: *opts,
+            help_url: help_urls.for_rule("subject-full-stop"),
+        }));
+    }
+    if let Some(opts) = &details.subject_case {
+        rules.push(Box::new(SubjectCaseRule {
+            opts: *opts,
+            help_url: help_urls.for_rule("subject-case"),
+        }));
+    }
+    if let Some(opts) = &details.header_max_length {
+        rules.push(Box::new(HeaderMaxLengthRule {
+            opts: *opts,
+            help_url: help_urls.for_rule("header-max-length"),
+        }));
+    }
+    if let Some(opts) = &details.body_leading_blank {
+        rules.push(Box::new(BodyLeadingBlankRule {
+            opts: *opts,
+            help_url: help_urls.for_rule("body-leading-blank"),
+        }));
+    }
+    if let Some(opts) = &details.body_max_line_length {
+        rules.push(Box::new(BodyMaxLineLengthRule {
+            opts: *opts,
+            help_url: help_urls.for_rule("body-max-line-length"),
+        }));
+    }
+    if let Some(opts) = &details.footer_leading_blank {
+        rules.push(Box::new(FooterLeadingBlankRule {
+            opts: *opts,
+            help_url: help_urls.for_rule("footer-leading-blank"),
+        }));
+    }
+
+    rules
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::settings::{Condition, Severity};
+
+    #[test]
+    fn builds_no_rules_from_an_empty_config() {
+        let rules = build_rules(&RulesDetails::default(), &HelpUrls::default());
+        assert!(rules.is_empty());
+    }
+
+    #[test]
+    fn builds_only_the_rules_present_in_config() {
+        let details = RulesDetails {
+            type_empty: Some((Severity::Error, Condition::Never)),
+            ..Default::default()
+        };
+
+        let rules = build_rules(&details, &HelpUrls::default());
+
+        assert_eq!(rules.len(), 1);
+        assert_eq!(rules[0].name(), "type-empty");
+    }
+}
@@ -0,0 +1,80 @@
+use crate::parser::Commit;
+use crate::rule::Rule;
+use crate::settings::{CaseOpts, Condition, Severity};
+use miette::{miette, LabeledSpan, Report};
+
+/// `scope-case`: the scope must (not) follow a configured case style.
+pub struct ScopeCaseRule {
+    pub opts: CaseOpts,
+    pub help_url: String,
+}
+
+impl Rule for ScopeCaseRule {
+    fn name(&self) -> &'static str {
+        "scope-case"
+    }
+
+    fn run(&self, commit: &Commit) -> Option<Report> {
+        let (severity, condition, case) = &self.opts;
+        if severity == &Severity::Off {
+            return None;
+        }
+
+        let scope = commit.scope.as_ref()?;
+        let matches = case.matches(&scope.to_string());
+        let is_valid = match condition {
+            Condition::Never => !matches,
+            Condition::Always => matches,
+        };
+        if is_valid {
+            return None;
+        }
+
+        Some(
+            miette!(
+                severity = severity.to_miette(),
+                labels = vec![LabeledSpan::at(scope.start()..scope.end(), "wrong case")],
+                help = format!(
+                    "scope must{} be {:?}",
+                    match condition {
+                        Condition::Never => " not",
+                        Condition::Always => "",
+                    },
+                    case
+                ),
+                code = "rule/scope-case",
+                url = self.help_url.clone(),
+                "Scope case is invalid",
+            )
+            .with_source_code(commit.raw.clone()),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::parse_commit;
+    use crate::settings::Case;
+
+    fn rule(case: Case) -> ScopeCaseRule {
+        ScopeCaseRule {
+            opts: (Severity::Error, Condition::Always, case),
+            help_url: String::new(),
+        }
+    }
+
+    #[test]
+    fn allows_a_scope_already_in_the_configured_case() {
+        let rule = rule(Case::LowerCase);
+        let commit = parse_commit("feat(nice): add cool feature");
+        assert!(rule.run(&commit).is_none());
+    }
+
+    #[test]
+    fn rejects_a_scope_in_the_wrong_case() {
+        let rule = rule(Case::LowerCase);
+        let commit = parse_commit("feat(NICE): add cool feature");
+        assert!(rule.run(&commit).is_some());
+    }
+}
@@ -0,0 +1,85 @@
+use crate::parser::Commit;
+use crate::rule::Rule;
+use crate::settings::{Condition, EnumOpts, Severity};
+use miette::{miette, LabeledSpan, Report};
+
+/// `scope-enum`: the scope must (not) be one of a configured list.
+pub struct ScopeEnumRule {
+    pub opts: EnumOpts,
+    pub help_url: String,
+}
+
+impl Rule for ScopeEnumRule {
+    fn name(&self) -> &'static str {
+        "scope-enum"
+    }
+
+    fn run(&self, commit: &Commit) -> Option<Report> {
+        let (severity, condition, scopes) = &self.opts;
+        if severity == &Severity::Off {
+            return None;
+        }
+
+        let scope = commit.scope.as_ref()?;
+        let is_in_scopes = scopes.contains(&scope.to_string());
+        let is_valid = match condition {
+            Condition::Never => !is_in_scopes,
+            Condition::Always => is_in_scopes,
+        };
+        if is_valid {
+            return None;
+        }
+
+        Some(
+            miette!(
+                severity = severity.to_miette(),
+                labels = vec![LabeledSpan::at(
+                    scope.start()..scope.end(),
+                    "not allowed scope"
+                )],
+                help = String::from("scope must")
+                    + match condition {
+                        Condition::Never => " not",
+                        Condition::Always => "",
+                    }
+                    + " be one of "
+                    + &scopes.join(", "),
+                code = "rule/scope-enum",
+                url = self.help_url.clone(),
+                "Scope not allowed",
+            )
+            .with_source_code(commit.raw.clone()),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::parse_commit;
+
+    fn rule(condition: Condition, scopes: &[&str]) -> ScopeEnumRule {
+        ScopeEnumRule {
+            opts: (
+                Severity::Error,
+                condition,
+                scopes.iter().map(|s| s.to_string()).collect(),
+            ),
+            help_url: String::new(),
+        }
+    }
+
+    #[test]
+    fn allows_a_scope_in_the_list() {
+        let rule = rule(Condition::Always, &["nice"]);
+        let commit = parse_commit("feat(nice): add cool feature");
+        assert!(rule.run(&commit).is_none());
+    }
+
+    #[test]
+    fn rejects_a_scope_outside_the_list() {
+        let rule = rule(Condition::Always, &["nice"]);
+        let commit = parse_commit("feat(other): add cool feature");
+        assert!(rule.run(&commit).is_some());
+    }
+}
@@ -0,0 +1,103 @@
+use crate::parser::{replace_span, Commit};
+use crate::rule::Rule;
+use crate::settings::{CaseOpts, Condition, Severity};
+use miette::{miette, LabeledSpan, Report};
+
+/// `subject-case`: the subject must (not) follow a configured case style.
+pub struct SubjectCaseRule {
+    pub opts: CaseOpts,
+    pub help_url: String,
+}
+
+impl Rule for SubjectCaseRule {
+    fn name(&self) -> &'static str {
+        "subject-case"
+    }
+
+    fn run(&self, commit: &Commit) -> Option<Report> {
+        let (severity, condition, case) = &self.opts;
+        if severity == &Severity::Off {
+            return None;
+        }
+
+        let subject = commit.subject.as_ref()?;
+        let matches = case.matches(&subject.to_string());
+        let is_valid = match condition {
+            Condition::Never => !matches,
+            Condition::Always => matches,
+        };
+        if is_valid {
+            return None;
+        }
+
+        Some(
+            miette!(
+                severity = severity.to_miette(),
+                labels = vec![LabeledSpan::at(
+                    subject.start()..subject.end(),
+                    "wrong case"
+                )],
+                help = format!(
+                    "subject must{} be {:?}",
+                    match condition {
+                        Condition::Never => " not",
+                        Condition::Always => "",
+                    },
+                    case
+                ),
+                code = "rule/subject-case",
+                url = self.help_url.clone(),
+                "Subject case is invalid",
+            )
+            .with_source_code(commit.raw.clone()),
+        )
+    }
+
+    fn fix(&self, commit: &Commit) -> Option<String> {
+        let (_, condition, case) = &self.opts;
+        if !matches!(condition, Condition::Always) {
+            return None;
+        }
+
+        let subject = commit.subject.as_ref()?;
+        let fixed = case.apply(&subject.to_string())?;
+        if fixed == subject.to_string() {
+            return None;
+        }
+
+        Some(replace_span(
+            &commit.raw,
+            subject.start()..subject.end(),
+            &fixed,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::parse_commit;
+    use crate::settings::Case;
+
+    fn rule(case: Case) -> SubjectCaseRule {
+        SubjectCaseRule {
+            opts: (Severity::Error, Condition::Always, case),
+            help_url: String::new(),
+        }
+    }
+
+    #[test]
+    fn allows_a_subject_already_in_the_configured_case() {
+        let rule = rule(Case::LowerCase);
+        let commit = parse_commit("feat: add cool feature");
+        assert!(rule.run(&commit).is_none());
+    }
+
+    #[test]
+    fn fixes_a_subject_into_the_configured_case() {
+        let rule = rule(Case::LowerCase);
+        let commit = parse_commit("feat: ADD COOL FEATURE");
+        assert!(rule.run(&commit).is_some());
+        assert_eq!(rule.fix(&commit).unwrap(), "feat: add cool feature");
+    }
+}
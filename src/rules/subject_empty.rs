@@ -0,0 +1,79 @@
+use crate::parser::Commit;
+use crate::rule::Rule;
+use crate::settings::{Condition, EmptyOpts, Severity};
+use miette::{miette, LabeledSpan, Report};
+
+/// `subject-empty`: the subject must (not) be empty.
+pub struct SubjectEmptyRule {
+    pub opts: EmptyOpts,
+    pub help_url: String,
+}
+
+impl Rule for SubjectEmptyRule {
+    fn name(&self) -> &'static str {
+        "subject-empty"
+    }
+
+    fn run(&self, commit: &Commit) -> Option<Report> {
+        let (severity, condition) = &self.opts;
+        if severity == &Severity::Off {
+            return None;
+        }
+
+        let is_present = commit.subject.is_some();
+        let is_valid = match condition {
+            Condition::Never => is_present,
+            Condition::Always => !is_present,
+        };
+        if is_valid {
+            return None;
+        }
+
+        let label = match &commit.subject {
+            Some(s) => LabeledSpan::at(s.start()..s.end(), "subject is present"),
+            None => LabeledSpan::at(0..commit.header.len(), "subject is missing"),
+        };
+
+        Some(
+            miette!(
+                severity = severity.to_miette(),
+                labels = vec![label],
+                help = match condition {
+                    Condition::Never => "subject must not be empty",
+                    Condition::Always => "subject must be empty",
+                },
+                code = "rule/subject-empty",
+                url = self.help_url.clone(),
+                "Subject emptiness is invalid",
+            )
+            .with_source_code(commit.raw.clone()),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::parse_commit;
+
+    fn rule(condition: Condition) -> SubjectEmptyRule {
+        SubjectEmptyRule {
+            opts: (Severity::Error, condition),
+            help_url: String::new(),
+        }
+    }
+
+    #[test]
+    fn allows_a_present_subject_when_never_empty() {
+        let rule = rule(Condition::Never);
+        let commit = parse_commit("feat: add cool feature");
+        assert!(rule.run(&commit).is_none());
+    }
+
+    #[test]
+    fn rejects_a_missing_subject_when_never_empty() {
+        let rule = rule(Condition::Never);
+        let commit = parse_commit("feat: ");
+        assert!(rule.run(&commit).is_some());
+    }
+}
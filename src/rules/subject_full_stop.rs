@@ -0,0 +1,106 @@
+use crate::parser::{replace_span, Commit};
+use crate::rule::Rule;
+use crate::settings::{Condition, FullStopOpts, Severity};
+use miette::{miette, LabeledSpan, Report};
+
+/// `subject-full-stop`: the subject must (not) end with a configured character.
+pub struct SubjectFullStopRule {
+    pub opts: FullStopOpts,
+    pub help_url: String,
+}
+
+impl Rule for SubjectFullStopRule {
+    fn name(&self) -> &'static str {
+        "subject-full-stop"
+    }
+
+    fn run(&self, commit: &Commit) -> Option<Report> {
+        let (severity, condition, full_stop) = &self.opts;
+        if severity == &Severity::Off {
+            return None;
+        }
+
+        let subject = commit.subject.as_ref()?;
+        let ends_with_full_stop = subject.value().ends_with(*full_stop);
+        let is_valid = match condition {
+            Condition::Never => !ends_with_full_stop,
+            Condition::Always => ends_with_full_stop,
+        };
+        if is_valid {
+            return None;
+        }
+
+        Some(
+            miette!(
+                severity = severity.to_miette(),
+                labels = vec![LabeledSpan::at(
+                    subject.end().saturating_sub(1)..subject.end(),
+                    "here"
+                )],
+                help = format!(
+                    "subject must{} end with '{full_stop}'",
+                    match condition {
+                        Condition::Never => " not",
+                        Condition::Always => "",
+                    }
+                ),
+                code = "rule/subject-full-stop",
+                url = self.help_url.clone(),
+                "Subject full stop is invalid",
+            )
+            .with_source_code(commit.raw.clone()),
+        )
+    }
+
+    fn fix(&self, commit: &Commit) -> Option<String> {
+        let (_, condition, full_stop) = &self.opts;
+        let subject = commit.subject.as_ref()?;
+
+        let fixed = match condition {
+            Condition::Never if subject.value().ends_with(*full_stop) => {
+                let mut fixed = subject.value().clone();
+                fixed.pop();
+                fixed
+            }
+            Condition::Always if !subject.value().ends_with(*full_stop) => {
+                let mut fixed = subject.value().clone();
+                fixed.push(*full_stop);
+                fixed
+            }
+            _ => return None,
+        };
+
+        Some(replace_span(
+            &commit.raw,
+            subject.start()..subject.end(),
+            &fixed,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::parse_commit;
+
+    fn rule(condition: Condition) -> SubjectFullStopRule {
+        SubjectFullStopRule {
+            opts: (Severity::Error, condition, '.'),
+            help_url: String::new(),
+        }
+    }
+
+    #[test]
+    fn rejects_a_trailing_full_stop_when_never_allowed() {
+        let rule = rule(Condition::Never);
+        let commit = parse_commit("feat: add cool feature.");
+        assert!(rule.run(&commit).is_some());
+    }
+
+    #[test]
+    fn fixes_a_trailing_full_stop_when_never_allowed() {
+        let rule = rule(Condition::Never);
+        let commit = parse_commit("feat: add cool feature.");
+        assert_eq!(rule.fix(&commit).unwrap(), "feat: add cool feature");
+    }
+}
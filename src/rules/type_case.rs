@@ -0,0 +1,100 @@
+use crate::parser::{replace_span, Commit};
+use crate::rule::Rule;
+use crate::settings::{CaseOpts, Condition, Severity};
+use miette::{miette, LabeledSpan, Report};
+
+/// `type-case`: the type must (not) follow a configured case style.
+pub struct TypeCaseRule {
+    pub opts: CaseOpts,
+    pub help_url: String,
+}
+
+impl Rule for TypeCaseRule {
+    fn name(&self) -> &'static str {
+        "type-case"
+    }
+
+    fn run(&self, commit: &Commit) -> Option<Report> {
+        let (severity, condition, case) = &self.opts;
+        if severity == &Severity::Off {
+            return None;
+        }
+
+        let r#type = commit.r#type.as_ref()?;
+        let matches = case.matches(&r#type.to_string());
+        let is_valid = match condition {
+            Condition::Never => !matches,
+            Condition::Always => matches,
+        };
+        if is_valid {
+            return None;
+        }
+
+        Some(
+            miette!(
+                severity = severity.to_miette(),
+                labels = vec![LabeledSpan::at(r#type.start()..r#type.end(), "wrong case")],
+                help = format!(
+                    "type must{} be {:?}",
+                    match condition {
+                        Condition::Never => " not",
+                        Condition::Always => "",
+                    },
+                    case
+                ),
+                code = "rule/type-case",
+                url = self.help_url.clone(),
+                "Type case is invalid",
+            )
+            .with_source_code(commit.raw.clone()),
+        )
+    }
+
+    fn fix(&self, commit: &Commit) -> Option<String> {
+        let (_, condition, case) = &self.opts;
+        if !matches!(condition, Condition::Always) {
+            return None;
+        }
+
+        let r#type = commit.r#type.as_ref()?;
+        let fixed = case.apply(&r#type.to_string())?;
+        if fixed == r#type.to_string() {
+            return None;
+        }
+
+        Some(replace_span(
+            &commit.raw,
+            r#type.start()..r#type.end(),
+            &fixed,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::parse_commit;
+    use crate::settings::Case;
+
+    fn rule(case: Case) -> TypeCaseRule {
+        TypeCaseRule {
+            opts: (Severity::Error, Condition::Always, case),
+            help_url: String::new(),
+        }
+    }
+
+    #[test]
+    fn allows_a_type_already_in_the_configured_case() {
+        let rule = rule(Case::LowerCase);
+        let commit = parse_commit("feat: add cool feature");
+        assert!(rule.run(&commit).is_none());
+    }
+
+    #[test]
+    fn fixes_a_type_into_the_configured_case() {
+        let rule = rule(Case::LowerCase);
+        let commit = parse_commit("FEAT: add cool feature");
+        assert!(rule.run(&commit).is_some());
+        assert_eq!(rule.fix(&commit).unwrap(), "feat: add cool feature");
+    }
+}
@@ -0,0 +1,85 @@
+use crate::parser::Commit;
+use crate::rule::Rule;
+use crate::settings::{Condition, EnumOpts, Severity};
+use miette::{miette, LabeledSpan, Report};
+
+/// `type-enum`: the type must (not) be one of a configured list.
+pub struct TypeEnumRule {
+    pub opts: EnumOpts,
+    pub help_url: String,
+}
+
+impl Rule for TypeEnumRule {
+    fn name(&self) -> &'static str {
+        "type-enum"
+    }
+
+    fn run(&self, commit: &Commit) -> Option<Report> {
+        let (severity, condition, types) = &self.opts;
+        if severity == &Severity::Off {
+            return None;
+        }
+
+        let r#type = commit.r#type.as_ref()?;
+        let is_in_types = types.contains(&r#type.to_string());
+        let is_valid = match condition {
+            Condition::Never => !is_in_types,
+            Condition::Always => is_in_types,
+        };
+        if is_valid {
+            return None;
+        }
+
+        Some(
+            miette!(
+                severity = severity.to_miette(),
+                labels = vec![LabeledSpan::at(
+                    r#type.start()..r#type.end(),
+                    "not allowed type"
+                )],
+                help = String::from("type must")
+                    + match condition {
+                        Condition::Never => " not",
+                        Condition::Always => "",
+                    }
+                    + " be one of "
+                    + &types.join(", "),
+                code = "rule/type-enum",
+                url = self.help_url.clone(),
+                "Type not allowed",
+            )
+            .with_source_code(commit.raw.clone()),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::parse_commit;
+
+    fn rule(condition: Condition, types: &[&str]) -> TypeEnumRule {
+        TypeEnumRule {
+            opts: (
+                Severity::Error,
+                condition,
+                types.iter().map(|t| t.to_string()).collect(),
+            ),
+            help_url: String::new(),
+        }
+    }
+
+    #[test]
+    fn allows_a_type_in_the_list() {
+        let rule = rule(Condition::Always, &["feat", "fix"]);
+        let commit = parse_commit("feat: add cool feature");
+        assert!(rule.run(&commit).is_none());
+    }
+
+    #[test]
+    fn rejects_a_type_outside_the_list() {
+        let rule = rule(Condition::Always, &["feat", "fix"]);
+        let commit = parse_commit("docs: add cool feature");
+        assert!(rule.run(&commit).is_some());
+    }
+}
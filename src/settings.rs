@@ -0,0 +1,225 @@
+//! Deserializable configuration shared by every rule.
+
+use serde::Deserialize;
+
+/// Severity of a rule.
+#[derive(Debug, Deserialize, PartialEq, Eq, Clone, Copy)]
+pub enum Severity {
+    /// Turn off the rule
+    #[serde(rename = "off")]
+    Off,
+    /// Warn about the violation of a rule
+    #[serde(rename = "warning")]
+    Warning,
+    /// Error about the violation of a rule
+    #[serde(rename = "error")]
+    Error,
+}
+
+impl Severity {
+    /// Maps our severity to the one `miette` expects on a diagnostic.
+    pub fn to_miette(self) -> miette::Severity {
+        match self {
+            Severity::Off => miette::Severity::Advice,
+            Severity::Warning => miette::Severity::Warning,
+            Severity::Error => miette::Severity::Error,
+        }
+    }
+}
+
+/// When the rule should be applied
+#[derive(Debug, Deserialize, Clone, Copy)]
+pub enum Condition {
+    /// The options should "never" be found (e.g. in a list of disallowed values)
+    #[serde(rename = "never")]
+    Never,
+    /// The options should "always" be found (e.g. in a list of allowed values)
+    #[serde(rename = "always")]
+    Always,
+}
+
+/// Case styles that `*-case` rules can enforce.
+// `-Case` is the case style's actual name (kebab-case, snake-case, ...), not redundant noise.
+#[allow(clippy::enum_variant_names)]
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq)]
+pub enum Case {
+    #[serde(rename = "lower-case")]
+    LowerCase,
+    #[serde(rename = "upper-case")]
+    UpperCase,
+    #[serde(rename = "camel-case")]
+    CamelCase,
+    #[serde(rename = "kebab-case")]
+    KebabCase,
+    #[serde(rename = "pascal-case")]
+    PascalCase,
+    #[serde(rename = "sentence-case")]
+    SentenceCase,
+    #[serde(rename = "snake-case")]
+    SnakeCase,
+}
+
+impl Case {
+    /// Whether `value` already satisfies this case style.
+    pub fn matches(self, value: &str) -> bool {
+        match self {
+            Case::LowerCase => value == value.to_lowercase(),
+            Case::UpperCase => value == value.to_uppercase(),
+            Case::CamelCase => {
+                value.chars().next().is_some_and(|c| c.is_lowercase())
+                    && !value.contains(['-', '_', ' '])
+            }
+            Case::KebabCase => value == value.to_lowercase() && !value.contains(['_', ' ']),
+            Case::PascalCase => {
+                value.chars().next().is_some_and(|c| c.is_uppercase())
+                    && !value.contains(['-', '_', ' '])
+            }
+            Case::SentenceCase => {
+                value.chars().next().is_some_and(|c| c.is_uppercase())
+                    && value.chars().skip(1).all(|c| !c.is_uppercase())
+            }
+            Case::SnakeCase => value == value.to_lowercase() && !value.contains(['-', ' ']),
+        }
+    }
+
+    /// Rewrites `value` into this case style, if the style can be applied
+    /// deterministically without guessing at word boundaries.
+    pub fn apply(self, value: &str) -> Option<String> {
+        match self {
+            Case::LowerCase => Some(value.to_lowercase()),
+            Case::UpperCase => Some(value.to_uppercase()),
+            Case::KebabCase => Some(value.to_lowercase().replace(['_', ' '], "-")),
+            Case::SnakeCase => Some(value.to_lowercase().replace(['-', ' '], "_")),
+            Case::CamelCase | Case::PascalCase | Case::SentenceCase => None,
+        }
+    }
+}
+
+/// Options for rules that check membership in an allow-/deny-list, e.g. `type-enum`.
+pub type EnumOpts = (Severity, Condition, Vec<String>);
+
+/// Options for rules that check a string's case, e.g. `type-case`.
+pub type CaseOpts = (Severity, Condition, Case);
+
+/// Options for rules that check presence/absence of a part of the commit.
+pub type EmptyOpts = (Severity, Condition);
+
+/// Options for rules that check a maximum length.
+pub type MaxLengthOpts = (Severity, Condition, usize);
+
+/// Options for `subject-full-stop`.
+pub type FullStopOpts = (Severity, Condition, char);
+
+/// How a rule may repair a commit that violates it.
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Fix {
+    /// The rule only reports; it never rewrites the commit message.
+    #[default]
+    #[serde(rename = "none")]
+    None,
+    /// The fix is applied automatically.
+    #[serde(rename = "safe")]
+    Safe,
+    /// The fix is only applied when the caller explicitly opts in.
+    #[serde(rename = "unsafe")]
+    Unsafe,
+}
+
+/// Config of all the rules. Rules left unset (`None`) are not run.
+#[derive(Debug, Deserialize, Default)]
+#[serde(deny_unknown_fields)]
+pub struct RulesDetails {
+    #[serde(rename = "type-enum")]
+    pub type_enum: Option<EnumOpts>,
+    #[serde(rename = "type-case")]
+    pub type_case: Option<CaseOpts>,
+    #[serde(rename = "type-empty")]
+    pub type_empty: Option<EmptyOpts>,
+    #[serde(rename = "scope-enum")]
+    pub scope_enum: Option<EnumOpts>,
+    #[serde(rename = "scope-case")]
+    pub scope_case: Option<CaseOpts>,
+    #[serde(rename = "subject-empty")]
+    pub subject_empty: Option<EmptyOpts>,
+    #[serde(rename = "subject-full-stop")]
+    pub subject_full_stop: Option<FullStopOpts>,
+    #[serde(rename = "subject-case")]
+    pub subject_case: Option<CaseOpts>,
+    #[serde(rename = "header-max-length")]
+    pub header_max_length: Option<MaxLengthOpts>,
+    #[serde(rename = "body-leading-blank")]
+    pub body_leading_blank: Option<EmptyOpts>,
+    #[serde(rename = "body-max-line-length")]
+    pub body_max_line_length: Option<MaxLengthOpts>,
+    #[serde(rename = "footer-leading-blank")]
+    pub footer_leading_blank: Option<EmptyOpts>,
+}
+
+/// Config
+#[derive(Debug, Deserialize)]
+pub struct RulesConfig {
+    pub rules: RulesDetails,
+    /// Per-rule autofix mode, keyed by rule name (e.g. `type-case`). Rules without an
+    /// entry default to [`Fix::None`].
+    #[serde(default)]
+    pub fix: std::collections::HashMap<String, Fix>,
+    /// Commits to skip linting for entirely, e.g. merges and bot-authored commits.
+    #[serde(default)]
+    pub ignore: IgnoreConfig,
+    /// Help URLs shown on each rule's diagnostic.
+    #[serde(rename = "helpUrl", default)]
+    pub help_url: HelpUrls,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+/// The default help URL used when neither a rule-specific nor a global `helpUrl` is
+/// configured.
+const FALLBACK_HELP_URL: &str = "https://github.com/zauni/commitlint-rs";
+
+/// Help URLs used as the `url` on a rule's diagnostic, so violations link to real
+/// documentation instead of a placeholder.
+#[derive(Debug, Deserialize, Default)]
+pub struct HelpUrls {
+    /// Used for any rule without a more specific override.
+    #[serde(default)]
+    pub default: Option<String>,
+    /// Per-rule overrides, keyed by rule name (e.g. `type-enum`).
+    #[serde(default)]
+    pub rules: std::collections::HashMap<String, String>,
+}
+
+impl HelpUrls {
+    /// Resolves the help URL for `rule`: its own override, then the global default,
+    /// then the crate's own documentation.
+    pub fn for_rule(&self, rule: &str) -> String {
+        self.rules
+            .get(rule)
+            .or(self.default.as_ref())
+            .cloned()
+            .unwrap_or_else(|| FALLBACK_HELP_URL.to_string())
+    }
+}
+
+/// Controls which commits are skipped before any rule runs, mirroring commitlint's
+/// `defaultIgnores`/`ignores` options.
+#[derive(Debug, Deserialize)]
+pub struct IgnoreConfig {
+    /// Whether the built-in ignore patterns (merges, reverts, fixups, bots, ...) apply.
+    #[serde(rename = "defaultIgnores", default = "default_true")]
+    pub default_ignores: bool,
+    /// Extra regexes matched against the commit header. Any match is ignored.
+    #[serde(default)]
+    pub ignores: Vec<String>,
+}
+
+impl Default for IgnoreConfig {
+    fn default() -> Self {
+        Self {
+            default_ignores: true,
+            ignores: Vec::new(),
+        }
+    }
+}
@@ -0,0 +1,104 @@
+//! Resolves the commit(s) to lint from a file path, stdin, or a `git log` range.
+
+use crate::parser::{parse_commit, Commit};
+use miette::{miette, IntoDiagnostic, Result};
+use std::io::Read;
+use std::path::Path;
+use std::process::Command;
+
+/// Reads a single commit message from `path`, e.g. `.git/COMMIT_EDITMSG` in a
+/// `commit-msg` hook.
+pub fn from_path(path: &Path) -> Result<Commit> {
+    let raw = std::fs::read_to_string(path).into_diagnostic()?;
+    Ok(parse_commit(&raw))
+}
+
+/// Reads a single commit message piped in on stdin.
+pub fn from_stdin() -> Result<Commit> {
+    let mut raw = String::new();
+    std::io::stdin()
+        .read_to_string(&mut raw)
+        .into_diagnostic()?;
+    Ok(parse_commit(&raw))
+}
+
+/// Field separator unlikely to appear in a commit message or its metadata.
+const FIELD_SEP: &str = "\u{1f}";
+/// Separator between commits in the `git log` output.
+const COMMIT_SEP: &str = "\u{1e}";
+
+/// Resolves every commit in `range` (e.g. `origin/main..HEAD`) via `git log`, oldest
+/// first, with SHA/author metadata attached for rules like signed-off-by checks.
+pub fn from_git_range(range: &str) -> Result<Vec<Commit>> {
+    let format = format!("%H{FIELD_SEP}%h{FIELD_SEP}%ae{FIELD_SEP}%B{COMMIT_SEP}");
+    let output = Command::new("git")
+        .args([
+            "log",
+            "--reverse",
+            &format!("--pretty=format:{format}"),
+            range,
+        ])
+        .output()
+        .into_diagnostic()?;
+
+    if !output.status.success() {
+        return Err(miette!(
+            "git log failed for range `{range}`: {}",
+            String::from_utf8_lossy(&output.stderr)
+        ));
+    }
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let commits = stdout
+        .split(COMMIT_SEP)
+        .map(str::trim)
+        .filter(|entry| !entry.is_empty())
+        .map(parse_git_log_entry)
+        .collect();
+
+    Ok(commits)
+}
+
+fn parse_git_log_entry(entry: &str) -> Commit {
+    let mut fields = entry.splitn(4, FIELD_SEP);
+    let sha = fields.next().unwrap_or_default().to_string();
+    let short_sha: String = fields.next().unwrap_or_default().chars().take(7).collect();
+    let author_email = fields.next().unwrap_or_default().to_string();
+    let message = fields.next().unwrap_or_default();
+
+    let mut commit = parse_commit(message);
+    commit.sha = Some(sha);
+    commit.short_sha = Some(short_sha);
+    commit.author_email = Some(author_email);
+    commit
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_a_git_log_entry_into_a_commit_with_metadata() {
+        let entry = format!(
+            "abcdef0123{FIELD_SEP}abcdef0{FIELD_SEP}jane@example.com{FIELD_SEP}feat: add cool feature"
+        );
+
+        let commit = parse_git_log_entry(&entry);
+
+        assert_eq!(commit.sha.as_deref(), Some("abcdef0123"));
+        assert_eq!(commit.short_sha.as_deref(), Some("abcdef0"));
+        assert_eq!(commit.author_email.as_deref(), Some("jane@example.com"));
+        assert_eq!(commit.r#type.unwrap().value(), "feat");
+    }
+
+    #[test]
+    fn reads_a_commit_message_from_a_file() {
+        let path = std::env::temp_dir().join("commitlint-rs-source-test-commit-msg");
+        std::fs::write(&path, "feat: add cool feature").unwrap();
+
+        let commit = from_path(&path).unwrap();
+
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(commit.r#type.unwrap().value(), "feat");
+    }
+}